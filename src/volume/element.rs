@@ -1,12 +1,15 @@
 //! This module defines the data element API, which enables NIfTI
 //! volume API implementations to read, write and convert data
 //! elements.
-use std::io::Read;
+use std::convert::TryFrom;
+use std::io::{Error as IoError, ErrorKind, Read};
 use std::ops::{Mul, Add};
 use std::mem::align_of;
 use byteorder::ReadBytesExt;
 use safe_transmute::guarded_transmute_pod_vec_permissive;
+use num_complex::Complex;
 use error::Result;
+use header::NiftiType;
 use num_traits::cast::AsPrimitive;
 use util::{Endianness, convert_bytes_to};
 
@@ -72,6 +75,70 @@ where
     }
 }
 
+/// Provides the inclusive bounds of an integer type as `f64`, used to clamp
+/// a rescaled floating-point value before converting it back to that type.
+pub trait FloatBounds {
+    /// The minimum value representable by this type, as an `f64`.
+    const MIN_AS_F64: f64;
+    /// The maximum value representable by this type, as an `f64`.
+    const MAX_AS_F64: f64;
+}
+
+macro_rules! impl_float_bounds {
+    ($($t:ident),+) => {
+        $(
+            impl FloatBounds for $t {
+                const MIN_AS_F64: f64 = ::std::$t::MIN as f64;
+                const MAX_AS_F64: f64 = ::std::$t::MAX as f64;
+            }
+        )+
+    }
+}
+
+impl_float_bounds!(u8, i8, u16, i16, u32, i32, u64, i64);
+
+/// Like `LinearTransformViaF32`, but clamps the rescaled value to the
+/// target type's inclusive range before converting back, so that a large
+/// `scl_slope`/`scl_inter` maps deterministically to the type's bounds
+/// instead of relying on an implementation-defined cast.
+#[derive(Debug)]
+pub struct LinearTransformViaF32Clamped;
+
+impl<T> LinearTransform<T> for LinearTransformViaF32Clamped
+where
+    T: AsPrimitive<f32> + FloatBounds,
+    f64: AsPrimitive<T>,
+{
+    fn linear_transform(value: T, slope: f32, intercept: f32) -> T {
+        if slope == 0. { return value }
+        let result: f32 = value.as_() * slope + intercept;
+        let clamped = (result as f64).max(T::MIN_AS_F64).min(T::MAX_AS_F64);
+        clamped.as_()
+    }
+}
+
+/// Like `LinearTransformViaF64`, but clamps the rescaled value to the
+/// target type's inclusive range before converting back, so that a large
+/// `scl_slope`/`scl_inter` maps deterministically to the type's bounds
+/// instead of relying on an implementation-defined cast.
+#[derive(Debug)]
+pub struct LinearTransformViaF64Clamped;
+
+impl<T> LinearTransform<T> for LinearTransformViaF64Clamped
+where
+    T: 'static + Copy + AsPrimitive<f64> + FloatBounds,
+    f64: AsPrimitive<T>,
+{
+    fn linear_transform(value: T, slope: f32, intercept: f32) -> T {
+        if slope == 0. { return value }
+        let slope: f64 = slope.as_();
+        let intercept: f64 = intercept.as_();
+        let result = value.as_() * slope + intercept;
+        let clamped = result.max(T::MIN_AS_F64).min(T::MAX_AS_F64);
+        clamped.as_()
+    }
+}
+
 /// A linear transformation in which the slope and intercept parameters are
 /// converted to the value's type for the affine transformation. Ideal
 /// for high precision or complex number types.
@@ -91,14 +158,34 @@ where
     }
 }
 
+/// A linear transformation that leaves the value unchanged. Used for data
+/// elements to which the affine slope/intercept do not apply, such as
+/// packed color values.
+#[derive(Debug)]
+pub struct LinearTransformIdentity;
+
+impl<T: 'static + Copy> LinearTransform<T> for LinearTransformIdentity {
+    fn linear_transform(value: T, _slope: f32, _intercept: f32) -> T {
+        value
+    }
+}
+
 /// Trait type for characterizing a NIfTI data element, implemented for
 /// primitive numeric types which are used by the crate to represent voxel
-/// values.
+/// values, as well as complex and packed color types. The `AsPrimitive`
+/// supertrait bounds are what let the volume subsystem rescale and read
+/// back any element as `u8`/`f32`/`f64` generically; complex and color
+/// element types satisfy them via the local `ComplexF32`/`ComplexF64`/
+/// `Rgb`/`Rgba` wrappers rather than by casting through a meaningful
+/// numeric value (e.g. a complex element yields its real part).
 pub trait DataElement: 'static + Sized + Copy + AsPrimitive<u8> + AsPrimitive<f32> + AsPrimitive<f64>
 {
     /// For defining how this element is linearly transformed to another.
     type Transform: LinearTransform<Self>;
 
+    /// The NIfTI data type that this element represents on disk.
+    const DATA_TYPE: NiftiType;
+
     /// Read a single element from the given byte source.
     fn from_raw<R: Read>(src: R, endianness: Endianness) -> Result<Self>;
 
@@ -108,10 +195,71 @@ pub trait DataElement: 'static + Sized + Copy + AsPrimitive<u8> + AsPrimitive<f3
         let n = align_of::<Self>();
         (0..n).map(|_| Self::from_raw(&mut cursor, endianness)).collect()
     }
+
+    /// Convert a `u8` value into this element type.
+    fn from_u8(x: u8) -> Self where u8: AsPrimitive<Self> { x.as_() }
+    /// Convert an `i8` value into this element type.
+    fn from_i8(x: i8) -> Self where i8: AsPrimitive<Self> { x.as_() }
+    /// Convert a `u16` value into this element type.
+    fn from_u16(x: u16) -> Self where u16: AsPrimitive<Self> { x.as_() }
+    /// Convert an `i16` value into this element type.
+    fn from_i16(x: i16) -> Self where i16: AsPrimitive<Self> { x.as_() }
+    /// Convert a `u32` value into this element type.
+    fn from_u32(x: u32) -> Self where u32: AsPrimitive<Self> { x.as_() }
+    /// Convert an `i32` value into this element type.
+    fn from_i32(x: i32) -> Self where i32: AsPrimitive<Self> { x.as_() }
+    /// Convert a `u64` value into this element type.
+    fn from_u64(x: u64) -> Self where u64: AsPrimitive<Self> { x.as_() }
+    /// Convert an `i64` value into this element type.
+    fn from_i64(x: i64) -> Self where i64: AsPrimitive<Self> { x.as_() }
+    /// Convert an `f32` value into this element type.
+    fn from_f32(x: f32) -> Self where f32: AsPrimitive<Self> { x.as_() }
+    /// Convert an `f64` value into this element type.
+    fn from_f64(x: f64) -> Self where f64: AsPrimitive<Self> { x.as_() }
+
+    /// Read a data vector whose storage layout is given by `datatype`
+    /// (the volume header's actual on-disk type), converting every value
+    /// into `Self`. This allows a caller to request a numeric target
+    /// element type whichever of the ten primitive numeric types the
+    /// volume is stored as. Any other stored type (complex or color data)
+    /// is read only when it equals `Self::DATA_TYPE`; otherwise an error
+    /// is returned.
+    fn from_raw_vec_validated(vec: Vec<u8>, endianness: Endianness, datatype: NiftiType) -> Result<Vec<Self>>
+    where
+        u8: AsPrimitive<Self>,
+        i8: AsPrimitive<Self>,
+        u16: AsPrimitive<Self>,
+        i16: AsPrimitive<Self>,
+        u32: AsPrimitive<Self>,
+        i32: AsPrimitive<Self>,
+        u64: AsPrimitive<Self>,
+        i64: AsPrimitive<Self>,
+        f32: AsPrimitive<Self>,
+        f64: AsPrimitive<Self>,
+    {
+        match datatype {
+            NiftiType::Uint8 => Ok(u8::from_raw_vec(vec, endianness)?.into_iter().map(Self::from_u8).collect()),
+            NiftiType::Int8 => Ok(i8::from_raw_vec(vec, endianness)?.into_iter().map(Self::from_i8).collect()),
+            NiftiType::Uint16 => Ok(u16::from_raw_vec(vec, endianness)?.into_iter().map(Self::from_u16).collect()),
+            NiftiType::Int16 => Ok(i16::from_raw_vec(vec, endianness)?.into_iter().map(Self::from_i16).collect()),
+            NiftiType::Uint32 => Ok(u32::from_raw_vec(vec, endianness)?.into_iter().map(Self::from_u32).collect()),
+            NiftiType::Int32 => Ok(i32::from_raw_vec(vec, endianness)?.into_iter().map(Self::from_i32).collect()),
+            NiftiType::Uint64 => Ok(u64::from_raw_vec(vec, endianness)?.into_iter().map(Self::from_u64).collect()),
+            NiftiType::Int64 => Ok(i64::from_raw_vec(vec, endianness)?.into_iter().map(Self::from_i64).collect()),
+            NiftiType::Float32 => Ok(f32::from_raw_vec(vec, endianness)?.into_iter().map(Self::from_f32).collect()),
+            NiftiType::Float64 => Ok(f64::from_raw_vec(vec, endianness)?.into_iter().map(Self::from_f64).collect()),
+            other if other == Self::DATA_TYPE => Self::from_raw_vec(vec, endianness),
+            other => Err(IoError::new(
+                ErrorKind::InvalidData,
+                format!("cannot read a volume of datatype {:?} into {:?} elements", other, Self::DATA_TYPE),
+            ).into()),
+        }
+    }
 }
 
 impl DataElement for u8 {
     type Transform = LinearTransformViaF32;
+    const DATA_TYPE: NiftiType = NiftiType::Uint8;
     fn from_raw_vec(vec: Vec<u8>, _: Endianness) -> Result<Vec<Self>> {
         Ok(vec)
     }
@@ -121,6 +269,7 @@ impl DataElement for u8 {
 }
 impl DataElement for i8 {
     type Transform = LinearTransformViaF32;
+    const DATA_TYPE: NiftiType = NiftiType::Int8;
     fn from_raw_vec(vec: Vec<u8>, _: Endianness) -> Result<Vec<Self>> {
         Ok(guarded_transmute_pod_vec_permissive(vec))
     }
@@ -130,6 +279,7 @@ impl DataElement for i8 {
 }
 impl DataElement for u16 {
     type Transform = LinearTransformViaF32;
+    const DATA_TYPE: NiftiType = NiftiType::Uint16;
     fn from_raw_vec(vec: Vec<u8>, e: Endianness) -> Result<Vec<Self>> {
         Ok(convert_bytes_to(vec, e))
     }
@@ -139,6 +289,7 @@ impl DataElement for u16 {
 }
 impl DataElement for i16 {
     type Transform = LinearTransformViaF32;
+    const DATA_TYPE: NiftiType = NiftiType::Int16;
     fn from_raw_vec(vec: Vec<u8>, e: Endianness) -> Result<Vec<Self>> {
         Ok(convert_bytes_to(vec, e))
     }
@@ -148,6 +299,7 @@ impl DataElement for i16 {
 }
 impl DataElement for u32 {
     type Transform = LinearTransformViaF32;
+    const DATA_TYPE: NiftiType = NiftiType::Uint32;
     fn from_raw_vec(vec: Vec<u8>, e: Endianness) -> Result<Vec<Self>> {
         Ok(convert_bytes_to(vec, e))
     }
@@ -157,6 +309,7 @@ impl DataElement for u32 {
 }
 impl DataElement for i32 {
     type Transform = LinearTransformViaF32;
+    const DATA_TYPE: NiftiType = NiftiType::Int32;
     fn from_raw_vec(vec: Vec<u8>, e: Endianness) -> Result<Vec<Self>> {
         Ok(convert_bytes_to(vec, e))
     }
@@ -166,6 +319,7 @@ impl DataElement for i32 {
 }
 impl DataElement for u64 {
     type Transform = LinearTransformViaF64;
+    const DATA_TYPE: NiftiType = NiftiType::Uint64;
     fn from_raw_vec(vec: Vec<u8>, e: Endianness) -> Result<Vec<Self>> {
         Ok(convert_bytes_to(vec, e))
     }
@@ -175,6 +329,7 @@ impl DataElement for u64 {
 }
 impl DataElement for i64 {
     type Transform = LinearTransformViaF64;
+    const DATA_TYPE: NiftiType = NiftiType::Int64;
     fn from_raw_vec(vec: Vec<u8>, e: Endianness) -> Result<Vec<Self>> {
         Ok(convert_bytes_to(vec, e))
     }
@@ -184,6 +339,7 @@ impl DataElement for i64 {
 }
 impl DataElement for f32 {
     type Transform = LinearTransformViaOriginal;
+    const DATA_TYPE: NiftiType = NiftiType::Float32;
     fn from_raw_vec(vec: Vec<u8>, e: Endianness) -> Result<Vec<Self>> {
         Ok(convert_bytes_to(vec, e))
     }
@@ -193,6 +349,7 @@ impl DataElement for f32 {
 }
 impl DataElement for f64 {
     type Transform = LinearTransformViaOriginal;
+    const DATA_TYPE: NiftiType = NiftiType::Float64;
     fn from_raw_vec(vec: Vec<u8>, e: Endianness) -> Result<Vec<Self>> {
         Ok(convert_bytes_to(vec, e))
     }
@@ -200,3 +357,440 @@ impl DataElement for f64 {
         e.read_f64(src).map_err(From::from)
     }
 }
+
+/// A linear transformation for complex-valued data elements. The slope is
+/// applied to both the real and imaginary parts, while the intercept is
+/// added to the real part only, matching the NIfTI `scl_slope`/`scl_inter`
+/// convention for complex data.
+#[derive(Debug)]
+pub struct LinearTransformComplex;
+
+impl LinearTransform<ComplexF32> for LinearTransformComplex {
+    fn linear_transform(value: ComplexF32, slope: f32, intercept: f32) -> ComplexF32 {
+        if slope == 0. { return value }
+        ComplexF32(Complex::new(value.0.re * slope + intercept, value.0.im * slope))
+    }
+}
+
+impl LinearTransform<ComplexF64> for LinearTransformComplex {
+    fn linear_transform(value: ComplexF64, slope: f32, intercept: f32) -> ComplexF64 {
+        if slope == 0. { return value }
+        let slope: f64 = slope.as_();
+        let intercept: f64 = intercept.as_();
+        ComplexF64(Complex::new(value.0.re * slope + intercept, value.0.im * slope))
+    }
+}
+
+/// A complex-valued data element backed by `num_complex::Complex<f32>`.
+/// Wrapped in a local newtype (rather than implementing `DataElement`
+/// directly on `Complex<f32>`) so it can also implement the crate's
+/// `AsPrimitive` conversions, which `num_complex`'s foreign type cannot
+/// do directly under Rust's orphan rules. Converting to a primitive
+/// yields the real part.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComplexF32(pub Complex<f32>);
+
+/// A complex-valued data element backed by `num_complex::Complex<f64>`.
+/// See [`ComplexF32`] for why this is a local wrapper rather than
+/// `Complex<f64>` directly. Converting to a primitive yields the real part.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComplexF64(pub Complex<f64>);
+
+impl AsPrimitive<u8> for ComplexF32 {
+    fn as_(self) -> u8 { self.0.re.as_() }
+}
+impl AsPrimitive<f32> for ComplexF32 {
+    fn as_(self) -> f32 { self.0.re }
+}
+impl AsPrimitive<f64> for ComplexF32 {
+    fn as_(self) -> f64 { self.0.re.as_() }
+}
+
+impl AsPrimitive<u8> for ComplexF64 {
+    fn as_(self) -> u8 { self.0.re.as_() }
+}
+impl AsPrimitive<f32> for ComplexF64 {
+    fn as_(self) -> f32 { self.0.re.as_() }
+}
+impl AsPrimitive<f64> for ComplexF64 {
+    fn as_(self) -> f64 { self.0.re }
+}
+
+impl DataElement for ComplexF32 {
+    type Transform = LinearTransformComplex;
+    const DATA_TYPE: NiftiType = NiftiType::Complex64;
+    fn from_raw_vec(vec: Vec<u8>, e: Endianness) -> Result<Vec<Self>> {
+        if vec.len() % 8 != 0 {
+            return Err(IoError::new(
+                ErrorKind::InvalidData,
+                "complex64 data length is not a multiple of 8 bytes",
+            ).into());
+        }
+        let parts: Vec<f32> = convert_bytes_to(vec, e);
+        Ok(parts.chunks_exact(2).map(|c| ComplexF32(Complex::new(c[0], c[1]))).collect())
+    }
+    fn from_raw<R: Read>(mut src: R, e: Endianness) -> Result<Self> {
+        let re = e.read_f32(&mut src)?;
+        let im = e.read_f32(&mut src)?;
+        Ok(ComplexF32(Complex::new(re, im)))
+    }
+}
+
+impl DataElement for ComplexF64 {
+    type Transform = LinearTransformComplex;
+    const DATA_TYPE: NiftiType = NiftiType::Complex128;
+    fn from_raw_vec(vec: Vec<u8>, e: Endianness) -> Result<Vec<Self>> {
+        if vec.len() % 16 != 0 {
+            return Err(IoError::new(
+                ErrorKind::InvalidData,
+                "complex128 data length is not a multiple of 16 bytes",
+            ).into());
+        }
+        let parts: Vec<f64> = convert_bytes_to(vec, e);
+        Ok(parts.chunks_exact(2).map(|c| ComplexF64(Complex::new(c[0], c[1]))).collect())
+    }
+    fn from_raw<R: Read>(mut src: R, e: Endianness) -> Result<Self> {
+        let re = e.read_f64(&mut src)?;
+        let im = e.read_f64(&mut src)?;
+        Ok(ComplexF64(Complex::new(re, im)))
+    }
+}
+
+/// A single RGB24 voxel value, with one byte per color channel. Endianness
+/// has no effect on its representation, since each channel is a single
+/// byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Rgb {
+    /// Red channel.
+    pub r: u8,
+    /// Green channel.
+    pub g: u8,
+    /// Blue channel.
+    pub b: u8,
+}
+
+impl AsPrimitive<u8> for Rgb {
+    fn as_(self) -> u8 { 0 }
+}
+impl AsPrimitive<f32> for Rgb {
+    fn as_(self) -> f32 { 0. }
+}
+impl AsPrimitive<f64> for Rgb {
+    fn as_(self) -> f64 { 0. }
+}
+
+impl DataElement for Rgb {
+    type Transform = LinearTransformIdentity;
+    const DATA_TYPE: NiftiType = NiftiType::Rgb24;
+    fn from_raw_vec(vec: Vec<u8>, _: Endianness) -> Result<Vec<Self>> {
+        if vec.len() % 3 != 0 {
+            return Err(IoError::new(
+                ErrorKind::InvalidData,
+                "rgb24 data length is not a multiple of 3 channels",
+            ).into());
+        }
+        Ok(vec.chunks_exact(3).map(|c| Rgb { r: c[0], g: c[1], b: c[2] }).collect())
+    }
+    fn from_raw<R: Read>(mut src: R, _: Endianness) -> Result<Self> {
+        let mut buf = [0u8; 3];
+        src.read_exact(&mut buf)?;
+        Ok(Rgb { r: buf[0], g: buf[1], b: buf[2] })
+    }
+}
+
+/// A single RGBA32 voxel value, with one byte per color channel plus alpha.
+/// Endianness has no effect on its representation, since each channel is a
+/// single byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Rgba {
+    /// Red channel.
+    pub r: u8,
+    /// Green channel.
+    pub g: u8,
+    /// Blue channel.
+    pub b: u8,
+    /// Alpha channel.
+    pub a: u8,
+}
+
+impl AsPrimitive<u8> for Rgba {
+    fn as_(self) -> u8 { 0 }
+}
+impl AsPrimitive<f32> for Rgba {
+    fn as_(self) -> f32 { 0. }
+}
+impl AsPrimitive<f64> for Rgba {
+    fn as_(self) -> f64 { 0. }
+}
+
+impl DataElement for Rgba {
+    type Transform = LinearTransformIdentity;
+    const DATA_TYPE: NiftiType = NiftiType::Rgba32;
+    fn from_raw_vec(vec: Vec<u8>, _: Endianness) -> Result<Vec<Self>> {
+        if vec.len() % 4 != 0 {
+            return Err(IoError::new(
+                ErrorKind::InvalidData,
+                "rgba32 data length is not a multiple of 4 channels",
+            ).into());
+        }
+        Ok(vec.chunks_exact(4).map(|c| Rgba { r: c[0], g: c[1], b: c[2], a: c[3] }).collect())
+    }
+    fn from_raw<R: Read>(mut src: R, _: Endianness) -> Result<Self> {
+        let mut buf = [0u8; 4];
+        src.read_exact(&mut buf)?;
+        Ok(Rgba { r: buf[0], g: buf[1], b: buf[2], a: buf[3] })
+    }
+}
+
+/// An owned NIfTI voxel value whose concrete type is only known at run
+/// time, for reading or converting volumes without monomorphizing over
+/// every `DataElement` implementation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NiftiValue {
+    /// An unsigned 8-bit value (`DT_UINT8`).
+    U8(u8),
+    /// A signed 8-bit value (`DT_INT8`).
+    I8(i8),
+    /// An unsigned 16-bit value (`DT_UINT16`).
+    U16(u16),
+    /// A signed 16-bit value (`DT_INT16`).
+    I16(i16),
+    /// An unsigned 32-bit value (`DT_UINT32`).
+    U32(u32),
+    /// A signed 32-bit value (`DT_INT32`).
+    I32(i32),
+    /// An unsigned 64-bit value (`DT_UINT64`).
+    U64(u64),
+    /// A signed 64-bit value (`DT_INT64`).
+    I64(i64),
+    /// A 32-bit floating point value (`DT_FLOAT32`).
+    F32(f32),
+    /// A 64-bit floating point value (`DT_FLOAT64`).
+    F64(f64),
+    /// A pair of 32-bit floating point values (`DT_COMPLEX64`).
+    Complex64(ComplexF32),
+    /// A pair of 64-bit floating point values (`DT_COMPLEX128`).
+    Complex128(ComplexF64),
+    /// A packed RGB color value (`DT_RGB24`).
+    Rgb24(Rgb),
+    /// A packed RGBA color value (`DT_RGBA32`).
+    Rgba32(Rgba),
+}
+
+impl NiftiValue {
+    /// Read a single dynamically-typed value from the given byte source,
+    /// dispatching on the runtime NIfTI `datatype`.
+    pub fn from_raw_value<R: Read>(src: R, endianness: Endianness, datatype: NiftiType) -> Result<NiftiValue> {
+        Ok(match datatype {
+            NiftiType::Uint8 => NiftiValue::U8(u8::from_raw(src, endianness)?),
+            NiftiType::Int8 => NiftiValue::I8(i8::from_raw(src, endianness)?),
+            NiftiType::Uint16 => NiftiValue::U16(u16::from_raw(src, endianness)?),
+            NiftiType::Int16 => NiftiValue::I16(i16::from_raw(src, endianness)?),
+            NiftiType::Uint32 => NiftiValue::U32(u32::from_raw(src, endianness)?),
+            NiftiType::Int32 => NiftiValue::I32(i32::from_raw(src, endianness)?),
+            NiftiType::Uint64 => NiftiValue::U64(u64::from_raw(src, endianness)?),
+            NiftiType::Int64 => NiftiValue::I64(i64::from_raw(src, endianness)?),
+            NiftiType::Float32 => NiftiValue::F32(f32::from_raw(src, endianness)?),
+            NiftiType::Float64 => NiftiValue::F64(f64::from_raw(src, endianness)?),
+            NiftiType::Complex64 => NiftiValue::Complex64(ComplexF32::from_raw(src, endianness)?),
+            NiftiType::Complex128 => NiftiValue::Complex128(ComplexF64::from_raw(src, endianness)?),
+            NiftiType::Rgb24 => NiftiValue::Rgb24(Rgb::from_raw(src, endianness)?),
+            NiftiType::Rgba32 => NiftiValue::Rgba32(Rgba::from_raw(src, endianness)?),
+            other => return Err(IoError::new(
+                ErrorKind::InvalidData,
+                format!("unsupported NIfTI datatype for dynamic read: {:?}", other),
+            ).into()),
+        })
+    }
+
+    /// Convert this value to `f64`, for generic numeric consumers. Complex
+    /// values yield their real part; RGB and RGBA values are not numeric
+    /// and yield `0.`.
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            NiftiValue::U8(x) => x.as_(),
+            NiftiValue::I8(x) => x.as_(),
+            NiftiValue::U16(x) => x.as_(),
+            NiftiValue::I16(x) => x.as_(),
+            NiftiValue::U32(x) => x.as_(),
+            NiftiValue::I32(x) => x.as_(),
+            NiftiValue::U64(x) => x.as_(),
+            NiftiValue::I64(x) => x.as_(),
+            NiftiValue::F32(x) => x.as_(),
+            NiftiValue::F64(x) => x,
+            NiftiValue::Complex64(x) => x.0.re.as_(),
+            NiftiValue::Complex128(x) => x.0.re,
+            NiftiValue::Rgb24(_) | NiftiValue::Rgba32(_) => 0.,
+        }
+    }
+
+    /// Convert this value to `f32`, for generic numeric consumers. Complex
+    /// values yield their real part; RGB and RGBA values are not numeric
+    /// and yield `0.`.
+    pub fn as_f32(&self) -> f32 {
+        match *self {
+            NiftiValue::U8(x) => x.as_(),
+            NiftiValue::I8(x) => x.as_(),
+            NiftiValue::U16(x) => x.as_(),
+            NiftiValue::I16(x) => x.as_(),
+            NiftiValue::U32(x) => x.as_(),
+            NiftiValue::I32(x) => x.as_(),
+            NiftiValue::U64(x) => x.as_(),
+            NiftiValue::I64(x) => x.as_(),
+            NiftiValue::F32(x) => x,
+            NiftiValue::F64(x) => x.as_(),
+            NiftiValue::Complex64(x) => x.0.re,
+            NiftiValue::Complex128(x) => x.0.re.as_(),
+            NiftiValue::Rgb24(_) | NiftiValue::Rgba32(_) => 0.,
+        }
+    }
+}
+
+macro_rules! impl_try_from_nifti_value {
+    ($variant:ident, $t:ty) => {
+        impl TryFrom<NiftiValue> for $t {
+            type Error = NiftiValue;
+
+            fn try_from(value: NiftiValue) -> ::std::result::Result<Self, Self::Error> {
+                match value {
+                    NiftiValue::$variant(x) => Ok(x),
+                    other => Err(other),
+                }
+            }
+        }
+    }
+}
+
+impl_try_from_nifti_value!(U8, u8);
+impl_try_from_nifti_value!(I8, i8);
+impl_try_from_nifti_value!(U16, u16);
+impl_try_from_nifti_value!(I16, i16);
+impl_try_from_nifti_value!(U32, u32);
+impl_try_from_nifti_value!(I32, i32);
+impl_try_from_nifti_value!(U64, u64);
+impl_try_from_nifti_value!(I64, i64);
+impl_try_from_nifti_value!(F32, f32);
+impl_try_from_nifti_value!(F64, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamped_rescale_saturates_to_bounds() {
+        let clamped = <LinearTransformViaF32Clamped as LinearTransform<u8>>::linear_transform(200, 10., 0.);
+        assert_eq!(clamped, u8::MAX);
+
+        let clamped = <LinearTransformViaF32Clamped as LinearTransform<u8>>::linear_transform(200, -10., 0.);
+        assert_eq!(clamped, u8::MIN);
+    }
+
+    #[test]
+    fn clamped_rescale_saturates_to_bounds_for_wide_integer_types() {
+        // i64::MAX doesn't round-trip through f32 exactly, so the clamp
+        // converts the clamped f64 straight to i64 (matching
+        // LinearTransformViaF64Clamped) rather than bouncing through f32
+        // first, which would be a needless extra rounding step for wide
+        // integer types.
+        let clamped = <LinearTransformViaF32Clamped as LinearTransform<i64>>::linear_transform(i64::MAX, 2., 0.);
+        assert_eq!(clamped, i64::MAX);
+
+        let clamped = <LinearTransformViaF32Clamped as LinearTransform<i64>>::linear_transform(i64::MIN, 2., 0.);
+        assert_eq!(clamped, i64::MIN);
+    }
+
+    #[test]
+    fn complex_rescale_applies_intercept_to_real_part_only() {
+        let value = ComplexF32(Complex::new(1.0f32, 2.0f32));
+        let result = LinearTransformComplex::linear_transform(value, 2., 3.);
+        assert_eq!(result, ComplexF32(Complex::new(5.0, 4.0)));
+    }
+
+    #[test]
+    fn complex_from_raw_vec_rejects_misaligned_byte_length() {
+        // 9 bytes is not a multiple of 8 (2 x f32), so this must be rejected
+        // up front rather than silently dropping the trailing byte.
+        let bytes = vec![0u8; 9];
+        assert!(ComplexF32::from_raw_vec(bytes, Endianness::LE).is_err());
+
+        // 17 bytes is not a multiple of 16 (2 x f64).
+        let bytes = vec![0u8; 17];
+        assert!(ComplexF64::from_raw_vec(bytes, Endianness::LE).is_err());
+    }
+
+    #[test]
+    fn rgb_from_raw_reads_one_pixel() {
+        let bytes = [10u8, 20, 30];
+        let value = Rgb::from_raw(&bytes[..], Endianness::LE).unwrap();
+        assert_eq!(value, Rgb { r: 10, g: 20, b: 30 });
+    }
+
+    #[test]
+    fn rgb_from_raw_vec_chunks_into_pixels() {
+        let bytes = vec![10u8, 20, 30, 40, 50, 60];
+        let values = Rgb::from_raw_vec(bytes, Endianness::LE).unwrap();
+        assert_eq!(values, vec![
+            Rgb { r: 10, g: 20, b: 30 },
+            Rgb { r: 40, g: 50, b: 60 },
+        ]);
+    }
+
+    #[test]
+    fn rgb_from_raw_vec_rejects_misaligned_byte_length() {
+        let bytes = vec![0u8; 4];
+        assert!(Rgb::from_raw_vec(bytes, Endianness::LE).is_err());
+    }
+
+    #[test]
+    fn rgba_from_raw_reads_one_pixel() {
+        let bytes = [10u8, 20, 30, 40];
+        let value = Rgba::from_raw(&bytes[..], Endianness::LE).unwrap();
+        assert_eq!(value, Rgba { r: 10, g: 20, b: 30, a: 40 });
+    }
+
+    #[test]
+    fn rgba_from_raw_vec_chunks_into_pixels() {
+        let bytes = vec![10u8, 20, 30, 40, 50, 60, 70, 80];
+        let values = Rgba::from_raw_vec(bytes, Endianness::LE).unwrap();
+        assert_eq!(values, vec![
+            Rgba { r: 10, g: 20, b: 30, a: 40 },
+            Rgba { r: 50, g: 60, b: 70, a: 80 },
+        ]);
+    }
+
+    #[test]
+    fn rgba_from_raw_vec_rejects_misaligned_byte_length() {
+        let bytes = vec![0u8; 5];
+        assert!(Rgba::from_raw_vec(bytes, Endianness::LE).is_err());
+    }
+
+    #[test]
+    fn from_raw_vec_validated_coerces_stored_type() {
+        let values: [i16; 3] = [1, -2, 300];
+        let mut bytes = Vec::new();
+        for v in &values {
+            bytes.extend_from_slice(&v.to_le_bytes());
+        }
+        let result = f32::from_raw_vec_validated(bytes, Endianness::LE, NiftiType::Int16).unwrap();
+        assert_eq!(result, vec![1.0, -2.0, 300.0]);
+    }
+
+    #[test]
+    fn from_raw_value_round_trips_sample_datatypes() {
+        let cases: Vec<(NiftiType, Vec<u8>, NiftiValue)> = vec![
+            (NiftiType::Uint8, vec![7u8], NiftiValue::U8(7)),
+            (NiftiType::Int16, 3i16.to_le_bytes().to_vec(), NiftiValue::I16(3)),
+            (NiftiType::Float32, 1.5f32.to_le_bytes().to_vec(), NiftiValue::F32(1.5)),
+        ];
+        for (datatype, bytes, expected) in cases {
+            let value = NiftiValue::from_raw_value(&bytes[..], Endianness::LE, datatype).unwrap();
+            assert_eq!(value, expected);
+        }
+    }
+
+    #[test]
+    fn try_from_rejects_mismatched_variant() {
+        let value = NiftiValue::U8(5);
+        assert!(i16::try_from(value).is_err());
+    }
+}